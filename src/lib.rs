@@ -1,16 +1,29 @@
-#[allow(non_snake_case)]
-use std::f64::consts::FRAC_1_SQRT_2;  // For Hadamard gate
-use rand::rngs::OsRng;
-use rand::{random, Rng};
+#![allow(non_snake_case)]
+//! # Quantum Memory Simulator
+//!
+//! A small state-vector simulator for quantum memory operations including
+//! superposition, entanglement, interference, and measurement collapse.
+//!
+//! The register is represented by a [`StateVector`] of `2^n` complex
+//! amplitudes, so phenomena like entanglement emerge naturally from the
+//! amplitudes rather than from per-bit bookkeeping.
+
+use std::collections::HashMap;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};  // For Hadamard and phase gates
+use rand::Rng;
+
+/// The largest register we allow. RAM doubles with every qubit, so the
+/// `2^n` amplitude vector becomes impractical well before 64 bits.
+pub const MAX_QUBITS: usize = 30;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-struct Complex {
+pub struct Complex {
     real: f64,
     imag: f64,
 }
 
 impl Complex {
-    fn new(real: f64, imag: f64) -> Self {
+    pub fn new(real: f64, imag: f64) -> Self {
         Self { real, imag }
     }
 
@@ -18,6 +31,7 @@ impl Complex {
         self.real * self.real + self.imag * self.imag
     }
 
+    #[cfg(test)]
     fn conj(&self) -> Complex {
         Complex::new(self.real, -self.imag)
     }
@@ -53,6 +67,442 @@ impl StateVector {
     }
 }
 
+/// The basis in which a qubit is measured.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeasurementBasis {
+    /// Computational (Z) basis: |0>, |1>.
+    Z,
+    /// X basis: |+>, |->.
+    X,
+    /// Y basis: |+i>, |-i>.
+    Y,
+}
+
+/// Simulates an `n`-qubit quantum memory register backed by a full
+/// `2^n`-amplitude state vector.
+///
+/// Superposition, entanglement and collapse all fall out of the amplitudes:
+/// measuring a qubit sums the probability mass consistent with each outcome,
+/// samples one, and renormalizes the surviving amplitudes.
+pub struct QMem {
+    /// Number of qubits in the register.
+    num_qubits: usize,
+    /// The `2^num_qubits` complex amplitudes of the register.
+    state: StateVector,
+}
+
+impl QMem {
+    /// Creates a new quantum memory register of `num_qubits` qubits,
+    /// initialized to |0...0>.
+    ///
+    /// Panics if `num_qubits` exceeds [`MAX_QUBITS`], since the amplitude
+    /// vector doubles in size with every additional qubit.
+    pub fn new(num_qubits: usize) -> Self {
+        if num_qubits > MAX_QUBITS {
+            panic!("Too many qubits: {num_qubits} (max {MAX_QUBITS})");
+        }
+        QMem {
+            num_qubits,
+            state: StateVector::new(num_qubits),
+        }
+    }
+
+    /// Creates a register initialized to the single computational basis
+    /// state |basis>.
+    ///
+    /// Panics if `num_qubits` exceeds [`MAX_QUBITS`] or if `basis` is not a
+    /// valid index into the `2^num_qubits` amplitudes.
+    pub fn with_state(num_qubits: usize, basis: u64) -> Self {
+        if num_qubits > MAX_QUBITS {
+            panic!("Too many qubits: {num_qubits} (max {MAX_QUBITS})");
+        }
+        let dim = 1u64 << num_qubits;
+        if basis >= dim {
+            panic!("Basis state {basis} out of range for {num_qubits} qubits");
+        }
+        let mut amplitudes = vec![Complex::new(0.0, 0.0); dim as usize];
+        amplitudes[basis as usize] = Complex::new(1.0, 0.0);
+        QMem {
+            num_qubits,
+            state: StateVector { amplitudes },
+        }
+    }
+
+    /// Builds a register directly from a vector of amplitudes.
+    ///
+    /// The length must be a power of two (one amplitude per basis state).
+    /// If `∑|aᵢ|²` is within a small tolerance of 1 the vector is accepted
+    /// as-is, otherwise it is renormalized. A zero vector,
+    /// a non-power-of-two length, or an over-large register is an error.
+    pub fn from_amplitudes(amplitudes: Vec<Complex>) -> Result<Self, String> {
+        let dim = amplitudes.len();
+        if dim == 0 || !dim.is_power_of_two() {
+            return Err(format!("Amplitude count {dim} is not a power of two"));
+        }
+        let num_qubits = dim.trailing_zeros() as usize;
+        if num_qubits > MAX_QUBITS {
+            return Err(format!("Too many qubits: {num_qubits} (max {MAX_QUBITS})"));
+        }
+
+        let norm_sq: f64 = amplitudes.iter().map(Complex::magnitude_squared).sum();
+        if norm_sq <= f64::EPSILON {
+            return Err("Amplitude vector is zero and cannot be normalized".to_string());
+        }
+
+        let mut amplitudes = amplitudes;
+        if (norm_sq - 1.0).abs() > 1e-9 {
+            let factor = 1.0 / norm_sq.sqrt();
+            for amp in &mut amplitudes {
+                *amp = amp.scale(factor);
+            }
+        }
+
+        Ok(QMem {
+            num_qubits,
+            state: StateVector { amplitudes },
+        })
+    }
+
+    /// Returns the number of qubits in the register.
+    pub fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    /// Measures a qubit in the computational (Z) basis, collapsing the
+    /// register.
+    ///
+    /// The probability of outcome 1 is the summed |amplitude|² over every
+    /// basis state whose `index` bit is set. After sampling an outcome the
+    /// inconsistent amplitudes are zeroed and the survivors are renormalized
+    /// by dividing by √(surviving probability).
+    ///
+    /// * `index` - The index of the qubit to measure (0-based)
+    /// * Returns the measured value (true for 1, false for 0)
+    pub fn measure(&mut self, index: usize) -> bool {
+        if index >= self.num_qubits {
+            panic!("Index out of bound"); // Prevent out-of-bounds access
+        }
+
+        let bit = 1usize << index;
+        // Probability of observing |1> on this qubit.
+        let mut prob_one = 0.0;
+        for (i, amp) in self.state.amplitudes.iter().enumerate() {
+            if i & bit != 0 {
+                prob_one += amp.magnitude_squared();
+            }
+        }
+
+        let outcome = rand::rng().random_bool(prob_one.clamp(0.0, 1.0));
+        let surviving = if outcome { prob_one } else { 1.0 - prob_one };
+        let norm = surviving.sqrt();
+
+        for (i, amp) in self.state.amplitudes.iter_mut().enumerate() {
+            let consistent = (i & bit != 0) == outcome;
+            if consistent && norm > 0.0 {
+                *amp = amp.scale(1.0 / norm);
+            } else {
+                *amp = Complex::new(0.0, 0.0);
+            }
+        }
+
+        outcome
+    }
+
+    /// Applies a single-qubit 2×2 unitary `u` (row-major `[u00, u01, u10,
+    /// u11]`) to qubit `k`.
+    ///
+    /// Iterates over every index whose bit `k` is 0, pairs it with
+    /// `i | (1 << k)`, and replaces `(a0, a1)` with
+    /// `(u00*a0 + u01*a1, u10*a0 + u11*a1)`.
+    fn apply_single(&mut self, k: usize, u: [Complex; 4]) {
+        if k >= self.num_qubits {
+            panic!("Index out of bound");
+        }
+        let bit = 1usize << k;
+        for i in 0..self.state.amplitudes.len() {
+            if i & bit == 0 {
+                let j = i | bit;
+                let a0 = self.state.amplitudes[i];
+                let a1 = self.state.amplitudes[j];
+                self.state.amplitudes[i] = u[0].mul(&a0).add(&u[1].mul(&a1));
+                self.state.amplitudes[j] = u[2].mul(&a0).add(&u[3].mul(&a1));
+            }
+        }
+    }
+
+    /// Applies a controlled single-qubit unitary `u` to `target`, gated on
+    /// `control`. This is the 4×4 controlled unitary expressed as the 2×2
+    /// block that acts only on basis states where the control bit is set.
+    fn apply_controlled(&mut self, control: usize, target: usize, u: [Complex; 4]) {
+        if control >= self.num_qubits || target >= self.num_qubits {
+            panic!("Index out of bound");
+        }
+        if control == target {
+            panic!("Control and target must differ");
+        }
+        let cbit = 1usize << control;
+        let tbit = 1usize << target;
+        for i in 0..self.state.amplitudes.len() {
+            if i & tbit == 0 && i & cbit != 0 {
+                let j = i | tbit;
+                let a0 = self.state.amplitudes[i];
+                let a1 = self.state.amplitudes[j];
+                self.state.amplitudes[i] = u[0].mul(&a0).add(&u[1].mul(&a1));
+                self.state.amplitudes[j] = u[2].mul(&a0).add(&u[3].mul(&a1));
+            }
+        }
+    }
+
+    /// Applies the Hadamard gate (H), placing `k` into an equal superposition.
+    pub fn hadamard(&mut self, k: usize) {
+        let s = Complex::new(FRAC_1_SQRT_2, 0.0);
+        self.apply_single(k, [s, s, s, s.scale(-1.0)]);
+    }
+
+    /// Applies the Pauli-X (NOT) gate, flipping `k`.
+    pub fn pauli_x(&mut self, k: usize) {
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        self.apply_single(k, [zero, one, one, zero]);
+    }
+
+    /// Applies the Pauli-Y gate to `k`.
+    pub fn pauli_y(&mut self, k: usize) {
+        let zero = Complex::new(0.0, 0.0);
+        let i = Complex::new(0.0, 1.0);
+        self.apply_single(k, [zero, i.scale(-1.0), i, zero]);
+    }
+
+    /// Applies the Pauli-Z gate to `k`.
+    pub fn pauli_z(&mut self, k: usize) {
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        self.apply_single(k, [one, zero, zero, one.scale(-1.0)]);
+    }
+
+    /// Applies the phase gate S (a π/2 phase on |1>) to `k`.
+    pub fn phase_s(&mut self, k: usize) {
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        let i = Complex::new(0.0, 1.0);
+        self.apply_single(k, [one, zero, zero, i]);
+    }
+
+    /// Applies the phase gate T (a π/4 phase on |1>) to `k`.
+    pub fn phase_t(&mut self, k: usize) {
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        let t = Complex::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2);
+        self.apply_single(k, [one, zero, zero, t]);
+    }
+
+    /// Applies the CNOT gate: flips `target` when `control` is |1>.
+    pub fn cnot(&mut self, control: usize, target: usize) {
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        self.apply_controlled(control, target, [zero, one, one, zero]);
+    }
+
+    /// Applies a controlled phase rotation of `angle` radians: multiplies
+    /// the amplitude by e^{i·angle} only when both `control` and `target`
+    /// are |1>.
+    pub fn controlled_phase(&mut self, control: usize, target: usize, angle: f64) {
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        let phase = Complex::new(angle.cos(), angle.sin());
+        self.apply_controlled(control, target, [one, zero, zero, phase]);
+    }
+
+    /// Applies the Quantum Fourier Transform to the given qubit subset.
+    ///
+    /// Uses the standard gate decomposition: for each target qubit (from the
+    /// front of `qubits` to the back) apply a Hadamard, then a controlled
+    /// phase rotation of `2π / 2^(d+1)` from each qubit `d` places after it
+    /// in `qubits`; finally reverse the qubit order with SWAP gates. The
+    /// first entry of `qubits` is thus treated as the most significant.
+    pub fn qft(&mut self, qubits: &[usize]) {
+        let m = qubits.len();
+        for a in 0..m {
+            let j = qubits[a];
+            self.hadamard(j);
+            for (offset, &k) in qubits[a + 1..].iter().enumerate() {
+                // Qubit at position a+1+offset is `offset + 1` steps away, so
+                // the controlled-phase angle is 2π / 2^(offset + 2).
+                let angle = 2.0 * PI / (1u64 << (offset + 2)) as f64;
+                self.controlled_phase(k, j, angle);
+            }
+        }
+        for i in 0..m / 2 {
+            self.swap(qubits[i], qubits[m - 1 - i]);
+        }
+    }
+
+    /// Applies the SWAP gate, exchanging qubits `a` and `b` by swapping the
+    /// amplitudes of every pair of basis states that differ only on those
+    /// two bits.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        if a >= self.num_qubits || b >= self.num_qubits {
+            panic!("Index out of bound");
+        }
+        if a == b {
+            return; // No need to swap
+        }
+        let abit = 1usize << a;
+        let bbit = 1usize << b;
+        for i in 0..self.state.amplitudes.len() {
+            // Only act on states with a=1, b=0 to hit each pair once.
+            if i & abit != 0 && i & bbit == 0 {
+                let j = (i & !abit) | bbit;
+                self.state.amplitudes.swap(i, j);
+            }
+        }
+    }
+
+    /// Samples `shots` full-register measurements from the current amplitude
+    /// distribution and returns a histogram keyed by the measured bitstring.
+    ///
+    /// Unlike [`measure`](Self::measure), this does not collapse the live
+    /// register: it builds the cumulative distribution over the `2^n`
+    /// probabilities `|amplitude_i|²` once, then draws every sample against
+    /// it. This mirrors the run-counts-per-state model of mature simulators.
+    pub fn run(&self, shots: usize) -> HashMap<u64, usize> {
+        let mut cumulative = Vec::with_capacity(self.state.amplitudes.len());
+        let mut acc = 0.0;
+        for amp in &self.state.amplitudes {
+            acc += amp.magnitude_squared();
+            cumulative.push(acc);
+        }
+
+        let mut rng = rand::rng();
+        let mut counts = HashMap::new();
+        for _ in 0..shots {
+            let r = rng.random::<f64>() * acc;
+            // First basis state whose cumulative probability exceeds `r`.
+            let idx = cumulative
+                .partition_point(|&p| p <= r)
+                .min(cumulative.len() - 1);
+            *counts.entry(idx as u64).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Measures a qubit in the given basis, collapsing the register.
+    ///
+    /// For the X basis the qubit is rotated with a Hadamard before the
+    /// projective Z measurement; for the Y basis it is rotated with S† then
+    /// H. The rotation is undone afterwards so the collapsed register is
+    /// expressed back in the computational basis.
+    ///
+    /// * Returns `true` for the |−> / |−i> eigenstate (outcome 1) and
+    ///   `false` for the |+> / |+i> eigenstate (outcome 0).
+    pub fn measure_in_basis(&mut self, index: usize, basis: MeasurementBasis) -> bool {
+        match basis {
+            MeasurementBasis::Z => self.measure(index),
+            MeasurementBasis::X => {
+                self.hadamard(index);
+                let outcome = self.measure(index);
+                self.hadamard(index);
+                outcome
+            }
+            MeasurementBasis::Y => {
+                let zero = Complex::new(0.0, 0.0);
+                let one = Complex::new(1.0, 0.0);
+                let neg_i = Complex::new(0.0, -1.0);
+                self.apply_single(index, [one, zero, zero, neg_i]); // S†
+                self.hadamard(index);
+                let outcome = self.measure(index);
+                // Undo the basis rotation: H then S.
+                self.hadamard(index);
+                self.phase_s(index);
+                outcome
+            }
+        }
+    }
+
+    /// Returns ⟨Z⟩ = P(0) − P(1) for a qubit, computed directly from the
+    /// amplitudes without collapsing the register.
+    pub fn expectation_z(&self, index: usize) -> f64 {
+        if index >= self.num_qubits {
+            panic!("Index out of bound");
+        }
+        let bit = 1usize << index;
+        let mut p0 = 0.0;
+        let mut p1 = 0.0;
+        for (i, amp) in self.state.amplitudes.iter().enumerate() {
+            if i & bit == 0 {
+                p0 += amp.magnitude_squared();
+            } else {
+                p1 += amp.magnitude_squared();
+            }
+        }
+        p0 - p1
+    }
+
+    /// Prepares a Bell pair on qubits `a` and `b`, producing
+    /// (|00> + |11>)/√2 on those qubits by applying H to `a` then CNOT(a→b).
+    pub fn bell_pair(&mut self, a: usize, b: usize) {
+        self.hadamard(a);
+        self.cnot(a, b);
+    }
+
+    /// Runs a Bell test: samples `shots` full-register measurements and
+    /// returns the fraction in which qubits `a` and `b` agree.
+    ///
+    /// For a genuine Bell pair this is 1.0 — the shared amplitudes make
+    /// measuring one qubit collapse the other — whereas uncorrelated qubits
+    /// tend toward 0.5.
+    pub fn bells_test(&self, a: usize, b: usize, shots: usize) -> f64 {
+        if a >= self.num_qubits || b >= self.num_qubits {
+            panic!("Index out of bound");
+        }
+        let abit = 1u64 << a;
+        let bbit = 1u64 << b;
+        let counts = self.run(shots);
+        let mut correlated = 0usize;
+        let mut total = 0usize;
+        for (state, n) in &counts {
+            total += n;
+            if (state & abit != 0) == (state & bbit != 0) {
+                correlated += n;
+            }
+        }
+        if total == 0 {
+            0.0
+        } else {
+            correlated as f64 / total as f64
+        }
+    }
+
+    /// Returns the index of the most-probable basis state. Intended as a
+    /// lightweight inspection helper for the demo binary.
+    pub fn most_likely(&self) -> usize {
+        let mut best = 0;
+        let mut best_p = 0.0;
+        for (i, amp) in self.state.amplitudes.iter().enumerate() {
+            let p = amp.magnitude_squared();
+            if p > best_p {
+                best_p = p;
+                best = i;
+            }
+        }
+        best
+    }
+
+    /// Prints the amplitudes of every basis state with non-negligible weight.
+    pub fn print(&self) {
+        for (i, amp) in self.state.amplitudes.iter().enumerate() {
+            if amp.magnitude_squared() > 1e-12 {
+                println!(
+                    "|{i:0width$b}>: {:.4} + {:.4}i",
+                    amp.real,
+                    amp.imag,
+                    width = self.num_qubits
+                );
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,4 +524,167 @@ mod tests {
         assert_eq!(sv.amplitudes.len(), 8); // 2^3 = 8
         assert_eq!(sv.amplitudes[0], Complex::new(1.0, 0.0)); // |000⟩ state
     }
+
+    #[test]
+    fn test_new_qmem() {
+        let qmem = QMem::new(3);
+        assert_eq!(qmem.num_qubits(), 3);
+        assert_eq!(qmem.state.amplitudes.len(), 8);
+        assert_eq!(qmem.state.amplitudes[0], Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_measure_ground_state() {
+        // |000> always measures 0 on every qubit and stays normalized.
+        let mut qmem = QMem::new(3);
+        for i in 0..3 {
+            assert!(!qmem.measure(i));
+        }
+        assert_eq!(qmem.state.amplitudes[0], Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_too_many_qubits() {
+        QMem::new(MAX_QUBITS + 1);
+    }
+
+    #[test]
+    fn test_with_state() {
+        let qmem = QMem::with_state(3, 0b101);
+        assert_eq!(qmem.num_qubits(), 3);
+        assert_eq!(qmem.state.amplitudes[0b101], Complex::new(1.0, 0.0));
+        assert!(qmem.state.amplitudes[0b100].magnitude_squared() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_with_state_out_of_range() {
+        QMem::with_state(2, 4);
+    }
+
+    #[test]
+    fn test_from_amplitudes_renormalizes() {
+        // Unnormalized equal superposition on one qubit.
+        let qmem = QMem::from_amplitudes(vec![
+            Complex::new(1.0, 0.0),
+            Complex::new(1.0, 0.0),
+        ])
+        .unwrap();
+        assert_eq!(qmem.num_qubits(), 1);
+        let total: f64 = qmem.state.amplitudes.iter().map(|a| a.magnitude_squared()).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_from_amplitudes_rejects_bad_length() {
+        assert!(QMem::from_amplitudes(vec![Complex::new(1.0, 0.0); 3]).is_err());
+    }
+
+    #[test]
+    fn test_hadamard_superposition() {
+        let mut qmem = QMem::new(1);
+        qmem.hadamard(0);
+        let half = FRAC_1_SQRT_2 * FRAC_1_SQRT_2;
+        assert!((qmem.state.amplitudes[0].magnitude_squared() - half).abs() < 1e-12);
+        assert!((qmem.state.amplitudes[1].magnitude_squared() - half).abs() < 1e-12);
+        // H is its own inverse: applying it again restores |0>.
+        qmem.hadamard(0);
+        assert!((qmem.state.amplitudes[0].real - 1.0).abs() < 1e-12);
+        assert!(qmem.state.amplitudes[1].magnitude_squared() < 1e-12);
+    }
+
+    #[test]
+    fn test_pauli_x_flips() {
+        let mut qmem = QMem::new(1);
+        qmem.pauli_x(0);
+        assert_eq!(qmem.state.amplitudes[0], Complex::new(0.0, 0.0));
+        assert_eq!(qmem.state.amplitudes[1], Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_cnot_entangles() {
+        // H on qubit 0 then CNOT(0 -> 1) builds (|00> + |11>)/√2.
+        let mut qmem = QMem::new(2);
+        qmem.hadamard(0);
+        qmem.cnot(0, 1);
+        let half = 0.5;
+        assert!((qmem.state.amplitudes[0b00].magnitude_squared() - half).abs() < 1e-12);
+        assert!((qmem.state.amplitudes[0b11].magnitude_squared() - half).abs() < 1e-12);
+        assert!(qmem.state.amplitudes[0b01].magnitude_squared() < 1e-12);
+        assert!(qmem.state.amplitudes[0b10].magnitude_squared() < 1e-12);
+    }
+
+    #[test]
+    fn test_expectation_z() {
+        let mut qmem = QMem::new(1);
+        assert!((qmem.expectation_z(0) - 1.0).abs() < 1e-12); // |0> -> +1
+        qmem.pauli_x(0);
+        assert!((qmem.expectation_z(0) + 1.0).abs() < 1e-12); // |1> -> -1
+        qmem.pauli_x(0);
+        qmem.hadamard(0);
+        assert!(qmem.expectation_z(0).abs() < 1e-12); // |+> -> 0
+    }
+
+    #[test]
+    fn test_measure_x_basis_is_deterministic_on_plus() {
+        // |+> is the +1 eigenstate of X, so an X-basis measurement always 0.
+        let mut qmem = QMem::new(1);
+        qmem.hadamard(0);
+        assert!(!qmem.measure_in_basis(0, MeasurementBasis::X));
+    }
+
+    #[test]
+    fn test_qft_of_ground_state_is_uniform() {
+        // QFT|0...0> is the equal superposition with all amplitudes 1/√N.
+        let mut qmem = QMem::new(3);
+        qmem.qft(&[0, 1, 2]);
+        let amp = 1.0 / (8.0f64).sqrt();
+        for a in &qmem.state.amplitudes {
+            assert!((a.real - amp).abs() < 1e-12);
+            assert!(a.imag.abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_run_histogram_bell() {
+        // A Bell pair only ever yields |00> or |11>, never the odd states,
+        // and sampling does not disturb the register.
+        let mut qmem = QMem::new(2);
+        qmem.hadamard(0);
+        qmem.cnot(0, 1);
+        let counts = qmem.run(1000);
+        assert_eq!(counts.values().sum::<usize>(), 1000);
+        assert_eq!(counts.get(&0b01).copied().unwrap_or(0), 0);
+        assert_eq!(counts.get(&0b10).copied().unwrap_or(0), 0);
+        assert!(counts.contains_key(&0b00) && counts.contains_key(&0b11));
+        // Register untouched: the amplitudes still hold the superposition.
+        assert!(qmem.state.amplitudes[0b11].magnitude_squared() > 0.4);
+    }
+
+    #[test]
+    fn test_bell_pair_is_perfectly_correlated() {
+        let mut qmem = QMem::new(2);
+        qmem.bell_pair(0, 1);
+        assert_eq!(qmem.bells_test(0, 1, 1000), 1.0);
+    }
+
+    #[test]
+    fn test_bell_pair_collapse_correlates() {
+        // Measuring one qubit of a Bell pair forces the other to match.
+        let mut qmem = QMem::new(2);
+        qmem.bell_pair(0, 1);
+        let first = qmem.measure(0);
+        let second = qmem.measure(1);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut qmem = QMem::new(2);
+        qmem.pauli_x(0); // |01>
+        qmem.swap(0, 1); // -> |10>
+        assert_eq!(qmem.state.amplitudes[0b10], Complex::new(1.0, 0.0));
+        assert!(qmem.state.amplitudes[0b01].magnitude_squared() < 1e-12);
+    }
 }